@@ -1,5 +1,7 @@
 mod project;
 
+use std::collections::HashMap;
+
 fn main() {
     let mut graph = project::Graph::new(); 
     graph.init("soc-redditHyperlinks-body.tsv"); // Populate Graph with data
@@ -25,4 +27,52 @@ fn main() {
 
     print!("The top 5 subreddits with the highest out closeness centrality are:\n{:?}\n", &out_closeness[0..5]);
     print!("The top 5 subreddits with the highest in closeness centrality are:\n{:?}\n", &in_closeness[0..5]);
+
+    // Betweenness centrality and PageRank
+
+    let betweenness = graph.betweenness_centrality();
+    let ranks = graph.pagerank(0.85, 100, 1e-6);
+
+    print!("The top 5 subreddits with the highest betweenness centrality are:\n{:?}\n", &betweenness[0..5]);
+    print!("The top 5 subreddits with the highest PageRank are:\n{:?}\n", &ranks[0..5]);
+
+    // Connected components
+
+    let weak_components = graph.weakly_connected_components();
+    let strong_components = graph.strongly_connected_components();
+
+    print!("The graph has {:?} weakly connected components\n", weak_components.len());
+    print!("The graph has {:?} strongly connected components\n", strong_components.len());
+
+    // Graphviz export, sized by PageRank
+
+    let rank_scores: HashMap<String, f32> = ranks.into_iter().collect();
+    let dot_settings = project::DotSettings { node_scores: Some(&rank_scores), ..project::DotSettings::new() };
+    let dot = graph.to_dot(&dot_settings);
+    std::fs::write("graph.dot", dot).expect("error writing graph.dot");
+
+    // Sentiment-weighted shortest paths: does routing away from hostility
+    // change how far the top subreddit reaches?
+
+    let start = out_deg[0].0.clone();
+    let distances_all = graph.weighted_shortest_paths(start.clone(), project::SentimentFilter::All);
+    let distances_positive = graph.weighted_shortest_paths(start.clone(), project::SentimentFilter::PositiveOnly);
+
+    print!("From {:?}, weighted shortest paths reach {:?} subreddits (All) vs {:?} (PositiveOnly)\n", start, distances_all.len(), distances_positive.len());
+
+    // Positive-only subnetwork: which subreddits stay central once hostile links are dropped?
+
+    let positive_graph = graph.positive_subgraph();
+    let positive_betweenness = positive_graph.betweenness_centrality();
+
+    print!("The top 5 subreddits with the highest betweenness centrality in the positive-only subnetwork are:\n{:?}\n", &positive_betweenness[0..5]);
+
+    // Subgraph over the dataset's earlier half, by TIMESTAMP
+
+    let min_timestamp = graph.edges.iter().map(|(_, _, timestamp, _)| *timestamp).min().unwrap_or(0);
+    let max_timestamp = graph.edges.iter().map(|(_, _, timestamp, _)| *timestamp).max().unwrap_or(0);
+    let midpoint = min_timestamp + (max_timestamp - min_timestamp) / 2;
+
+    let early_subgraph = graph.subgraph_in_range(min_timestamp, midpoint);
+    print!("The first half of the dataset's time range has {:?} subreddits\n", early_subgraph.size);
 }
\ No newline at end of file
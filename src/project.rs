@@ -1,28 +1,158 @@
 use csv::{self};
-use std::{collections::{HashMap, HashSet, BinaryHeap}, usize};
+use std::{collections::{HashMap, HashSet, BinaryHeap, VecDeque}, usize};
 use core::cmp::Reverse;
 use rayon::prelude::*;
 
-type Record = HashMap<String, String>; 
+type Record = HashMap<String, String>;
+
+// Wraps an f32 distance so it can sit inside a BinaryHeap, which requires Ord;
+// edge costs are never NaN so falling back to Equal on a failed comparison is safe.
+#[derive(PartialEq)]
+struct HeapDistance(f32);
+impl Eq for HeapDistance {}
+impl Ord for HeapDistance {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+impl PartialOrd for HeapDistance {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum SentimentFilter {
+    /*
+    pub enum SentimentFilter: controls how LINK_SENTIMENT affects edge costs
+        in weighted_shortest_paths.
+    variants:
+        All: every edge is traversable, but negative-sentiment edges cost
+            more, so shortest paths prefer the positive subnetwork without
+            losing reachability.
+        PositiveOnly: negative-sentiment edges are excluded entirely, so
+            distances are computed over only the positive hyperlink subnetwork.
+    */
+    All,
+    PositiveOnly,
+}
+
+fn parse_timestamp(value: &str) -> u64 {
+    /*
+    fn parse_timestamp:
+        parses a SNAP-format "YYYY-MM-DD HH:MM:SS" TIMESTAMP column value into
+        a Unix timestamp in seconds using plain calendar arithmetic, so no
+        extra date/time dependency is needed.
+    parameters:
+        value: the raw TIMESTAMP column value
+    returns:
+        seconds: the number of seconds since the Unix epoch (1970-01-01 UTC)
+    */
+
+    let mut halves = value.splitn(2, ' ');
+    let date_part = halves.next().unwrap_or("1970-01-01");
+    let time_part = halves.next().unwrap_or("00:00:00");
+
+    let mut date_fields = date_part.split('-');
+    let year: i64 = date_fields.next().unwrap_or("1970").parse().unwrap_or(1970);
+    let month: usize = date_fields.next().unwrap_or("1").parse().unwrap_or(1);
+    let day: i64 = date_fields.next().unwrap_or("1").parse().unwrap_or(1);
+
+    let mut time_fields = time_part.split(':');
+    let hour: u64 = time_fields.next().unwrap_or("0").parse().unwrap_or(0);
+    let minute: u64 = time_fields.next().unwrap_or("0").parse().unwrap_or(0);
+    let second: u64 = time_fields.next().unwrap_or("0").parse().unwrap_or(0);
+
+    let is_leap_year = |y: i64| (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+    let days_in_month = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+    let mut days: i64 = 0;
+    for y in 1970..year {
+        days += if is_leap_year(y) { 366 } else { 365 };
+    }
+    for (index, length) in days_in_month.iter().enumerate().take(month.saturating_sub(1)) {
+        days += length;
+        if index == 1 && is_leap_year(year) {
+            days += 1;
+        }
+    }
+    days += day - 1;
+
+    (days as u64) * 86_400 + hour * 3_600 + minute * 60 + second
+}
+
+// An internal, traversal-only view of a Graph: every node name is interned into
+// a dense usize id, and out-edges are laid out as a compressed-sparse-row
+// structure (a sorted targets array plus an offsets array of length N+1), so
+// node i's neighbors are targets[offsets[i]..offsets[i+1]]. BFS/Brandes build
+// this once per call and then operate entirely over ids and contiguous
+// slices, which removes per-edge string hashing and cloning from the hot path.
+struct GraphIndex {
+    id_to_name: Vec<String>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+}
+
+impl GraphIndex {
+    fn node_count(&self) -> usize {
+        self.id_to_name.len()
+    }
+
+    fn neighbors(&self, id: usize) -> &[usize] {
+        &self.targets[self.offsets[id]..self.offsets[id + 1]]
+    }
+
+    // backs pagerank()'s out-degree lookups; degree() (and so
+    // out_degree_centrality) reads the same offsets directly rather than
+    // through this method.
+    fn out_degree(&self, id: usize) -> usize {
+        self.offsets[id + 1] - self.offsets[id]
+    }
+
+    // groups every node's incoming edges by target id, so reverse() and
+    // pagerank() don't each re-derive this by hashing node strings.
+    fn incoming(&self) -> Vec<Vec<usize>> {
+        let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); self.node_count()];
+        for from_id in 0..self.node_count() {
+            for &to_id in self.neighbors(from_id) {
+                incoming[to_id].push(from_id);
+            }
+        }
+        incoming
+    }
+}
 
 pub struct Graph {
     /*
     pub struct Graph: a struct containing data about a graph network
     fields:
         size: an unsigned integer containing the number of nodes in the graph
-        adjacency dict: a hashmap with every node in the graph as keys and their 
+        adjacency dict: a hashmap with every node in the graph as keys and their
         corresponding connections in a vector of strings as the values.
         nodes: A hashset containing the name of every node in the graph.
+        weighted_adjacency: a hashmap mirroring adjacency_dict, but where each
+            connection also carries its LINK_SENTIMENT as an f32 edge weight.
+        edges: every (source, target, TIMESTAMP, LINK_SENTIMENT) tuple read
+            from the data file, kept around so subgraph_in_range can rebuild
+            a Graph over an arbitrary time window.
     */
     pub size: usize,
-    pub adjacency_dict: HashMap<String, Vec<String>>, 
-    pub nodes: HashSet<String>
+    pub adjacency_dict: HashMap<String, Vec<String>>,
+    pub nodes: HashSet<String>,
+    pub weighted_adjacency: HashMap<String, Vec<(String, f32)>>,
+    pub edges: Vec<(String, String, u64, f32)>,
 }
 
 impl Graph {
     pub fn new() -> Graph {
         // Initializes and returns an empty Graph struct
-        Graph { size: 0, nodes: HashSet::new(), adjacency_dict: HashMap::new()}
+        Graph {
+            size: 0,
+            nodes: HashSet::new(),
+            adjacency_dict: HashMap::new(),
+            weighted_adjacency: HashMap::new(),
+            edges: Vec::new(),
+        }
     }
 
     pub fn init(&mut self, path: &str) {
@@ -61,33 +191,116 @@ impl Graph {
         }
         
         for line in &raw_data_list {
-            
+
             let node = line.get("SOURCE_SUBREDDIT").expect("error getting source subreddit");
             let connection = line.get("TARGET_SUBREDDIT").expect("error getting target subreddit");
-        
+
             self.adjacency_dict
                 .get_mut(node)
                 .expect("error fetching node from adjacency dict")
                 .push(connection.to_string());
         }
+
+        for node in &self.nodes {
+            self.weighted_adjacency.insert(node.to_string(), Vec::new());
+        }
+
+        for line in &raw_data_list {
+            let source = line.get("SOURCE_SUBREDDIT").expect("error getting source subreddit");
+            let target = line.get("TARGET_SUBREDDIT").expect("error getting target subreddit");
+
+            let sentiment: f32 = line
+                .get("LINK_SENTIMENT")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(1.0);
+            let timestamp = line
+                .get("TIMESTAMP")
+                .map(|value| parse_timestamp(value))
+                .unwrap_or(0);
+
+            self.edges.push((source.to_string(), target.to_string(), timestamp, sentiment));
+            self.weighted_adjacency
+                .get_mut(source)
+                .expect("error fetching node from weighted adjacency dict")
+                .push((target.to_string(), sentiment));
+        }
+    }
+
+    fn build_index(&self) -> GraphIndex {
+        /*
+        fn build_index:
+            interns every node in adjacency_dict into a dense usize id and
+            lays its out-edges out in compressed-sparse-row form, so
+            traversal-heavy routines can run entirely over ids and
+            contiguous slices instead of hashing and cloning Strings.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+        returns:
+            index: a GraphIndex with id<->name lookups and the CSR edge layout
+        */
+
+        let mut id_to_name: Vec<String> = self.adjacency_dict.keys().cloned().collect();
+        id_to_name.sort(); // deterministic ids regardless of HashMap iteration order, and enables binary search lookups
+
+        let mut name_to_id: HashMap<&str, usize> = HashMap::new();
+        for (id, name) in id_to_name.iter().enumerate() {
+            name_to_id.insert(name.as_str(), id);
+        }
+
+        let mut offsets: Vec<usize> = vec![0; id_to_name.len() + 1];
+        let mut targets: Vec<usize> = Vec::new();
+
+        for (id, name) in id_to_name.iter().enumerate() {
+            let outedges = self.adjacency_dict.get(name).expect("outedges");
+            let mut neighbor_ids: Vec<usize> = outedges
+                .iter()
+                .map(|target| *name_to_id.get(target.as_str()).expect("target id"))
+                .collect();
+            neighbor_ids.sort_unstable();
+
+            targets.extend_from_slice(&neighbor_ids);
+            offsets[id + 1] = targets.len();
+        }
+
+        GraphIndex { id_to_name, offsets, targets }
+    }
+
+    fn shortest_paths_from_id(index: &GraphIndex, start_id: usize) -> Vec<i64> {
+        // unweighted BFS over the CSR layout; dist[id] is -1 until reached
+        let mut dist = vec![-1i64; index.node_count()];
+        dist[start_id] = 0;
+
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(start_id);
+
+        while let Some(node) = queue.pop_front() {
+            for &neighbor in index.neighbors(node) {
+                if dist[neighbor] < 0 {
+                    dist[neighbor] = dist[node] + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        dist
     }
 
     fn degree(&self) -> Vec<(String, usize)> {
         /*
-        fn degree: 
+        fn degree:
             calculates the (out) degree (# of outgoing connections) for every node in the graph
-        parameters: 
+        parameters:
             &self: a reference to the Graph struct on which this method is called
         returns:
-            degree: a vector of (String, usize) where String is the node and usize is its 
-                    out degree sorted in descending order by out degree. 
+            degree: a vector of (String, usize) where String is the node and usize is its
+                    out degree sorted in descending order by out degree.
         */
-        
-        let mut degree: Vec<(String, usize)> = Vec::new();
-        for node in &self.adjacency_dict {
-            degree.push((node.0.clone(), node.1.len()));
-        }
-        
+
+        let index = self.build_index();
+        let mut degree: Vec<(String, usize)> = (0..index.node_count())
+            .map(|id| (index.id_to_name[id].clone(), index.offsets[id + 1] - index.offsets[id]))
+            .collect();
+
         degree.sort_by_key(|v| std::cmp::Reverse(v.1));
         degree
     }
@@ -95,29 +308,32 @@ impl Graph {
     pub fn reverse(&self) -> Graph {
         /*
         pub fn reverse:
-            returns the reverse of the graph the method is called on
+            returns the reverse of the graph the method is called on, built by
+            grouping the CSR edge layout by target id rather than hashing
+            node strings for every edge.
         parameters:
             &self: reference to the Graph struct that the method is called on
         returns:
-            reverse: a Graph struct with the directions of the edges 
+            reverse: a Graph struct with the directions of the edges
                     reversed compared to the original graph
         */
-        
+
+        let index = self.build_index();
+        let incoming = index.incoming();
+
         let mut reverse = Graph::new();
         reverse.nodes = self.nodes.clone();
-        reverse.size = self.size.clone();
+        reverse.size = self.size;
 
-        for node in &self.nodes {
-            reverse.adjacency_dict.insert(node.to_string(), Vec::new());
+        for (id, name) in index.id_to_name.iter().enumerate() {
+            let outedges: Vec<String> = incoming[id].iter().map(|&from_id| index.id_to_name[from_id].clone()).collect();
+            reverse.adjacency_dict.insert(name.clone(), outedges);
         }
 
-        for node in &self.nodes { // for every node in the graph
-            let outedges = self.adjacency_dict.get(node).expect("msg"); // get all outgoing connections
-
-            for outedge in outedges.iter() { // for every outgoing connection
-                reverse.adjacency_dict.get_mut(outedge).expect("msg").push(node.to_string()); // push the node pointing to it
-            }
+        for node in &self.nodes {
+            reverse.adjacency_dict.entry(node.clone()).or_default();
         }
+
         reverse
     }
 
@@ -163,73 +379,555 @@ impl Graph {
     pub fn closeness_centrality(&self) -> Vec<(String, f32)>{
         /*
         pub fn closeness_centrality:
-            calculates the Wasserman-Faust adjusted closeness centrality of 
-            every node in the graph.
+            calculates the Wasserman-Faust adjusted closeness centrality of
+            every node in the graph. Builds one GraphIndex up front and reuses
+            it for every source's BFS, instead of hashing and cloning node
+            strings for each of the N per-source traversals.
         parameters:
             &self: a reference to the Graph struct that the method is called on
         returns:
-            centralities_sorted: An N dimensional vector of (String, f32) where 
-            String is the node and f32 is its WF closeness centrality, sorted by 
+            centralities_sorted: An N dimensional vector of (String, f32) where
+            String is the node and f32 is its WF closeness centrality, sorted by
             closeness centrality
         */
-        
+
         let graph = self.reverse(); // Incoming distance
-        let nodes = graph.nodes.clone(); // Clone to avoid borrowing issues in parallel processing
+        let index = graph.build_index();
         let big_n = graph.size as f32 - 1.0; // every reachable node
-        
-        let centralities: Vec<(String, f32)> = nodes.par_iter().map(|node| {
-            let shortest_paths = graph.shortest_paths(node.to_string()); 
-            
-            let sum_dists: usize = shortest_paths.values().sum();
-            let n = shortest_paths.len() as f32; // every reachable node
+
+        let centralities: Vec<(String, f32)> = (0..index.node_count()).into_par_iter().map(|start_id| {
+            let dist = Self::shortest_paths_from_id(&index, start_id);
+
+            let mut sum_dists: usize = 0;
+            let mut n: usize = 0;
+            for &d in &dist {
+                if d >= 0 {
+                    sum_dists += d as usize;
+                    n += 1;
+                }
+            }
+            let n = n as f32; // every reachable node
+
             let closeness_centrality: f32 = if sum_dists != 0 {
                 ((n - 1.0) / (big_n - 1.0)) * (n / sum_dists as f32)
             } else {
                 0.0
             };
-            
-            (node.clone(), closeness_centrality)
+
+            (index.id_to_name[start_id].clone(), closeness_centrality)
         }).collect();
-    
+
         let mut centralities_sorted = centralities;
         centralities_sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         centralities_sorted
     }
-    
-    fn shortest_paths(&self, start: String) -> HashMap<String, usize>{
+
+    // Edges with negative sentiment are still traversable under SentimentFilter::All,
+    // but cost this much more so shortest paths route around hostility where possible.
+    const NEGATIVE_SENTIMENT_PENALTY: f32 = 5.0;
+
+    pub fn weighted_shortest_paths(&self, start: String, filter: SentimentFilter) -> HashMap<String, f32> {
         /*
-            fn shortest_paths: calculates the shortest paths distance between a 
-                                node and every reachable node.
-            parameters:
-                &self: a reference to the graph that the method is called on.
-                start: A String containing the node from which to calculate distances from.
-            returns:
-                distances: A hashmap with all reachable nodes as keys and their shortest
-                paths distances from the start node as the corresponding values. 
+        pub fn weighted_shortest_paths:
+            calculates the Dijkstra shortest path distance between a node and
+            every reachable node, costing edges by their LINK_SENTIMENT:
+            SentimentFilter::All keeps every edge but penalizes negative-
+            sentiment links, while SentimentFilter::PositiveOnly drops them
+            so distances are computed over only the positive subnetwork.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+            start: a String containing the node from which to calculate distances from
+            filter: which edges participate in the traversal and how they're costed
+        returns:
+            distances: a hashmap with all reachable nodes as keys and their
+                       shortest weighted distances from start as values.
         */
-        let mut distances: HashMap<String, usize> = HashMap::new();
-        distances.insert(start.to_string(), 0);
 
-        let mut pq = BinaryHeap::<Reverse<(String,usize)>>::new(); //binary min heap of (String, usize)
-        pq.push(Reverse((start.clone(), 0))); // initialaizing the starting node with a distance of 0  
-        
-        while let Some(Reverse((node, dist))) = pq.pop() {
-            let outedges = self.adjacency_dict.get(&node).expect("outedges");
-            for outedge in outedges {
-                
-                let new_dist = dist + 1; // The length of every edge is always 1
+        let mut distances: HashMap<String, f32> = HashMap::new();
+        distances.insert(start.clone(), 0.0);
+
+        let mut pq = BinaryHeap::<Reverse<(HeapDistance, String)>>::new();
+        pq.push(Reverse((HeapDistance(0.0), start)));
+
+        while let Some(Reverse((HeapDistance(dist), node))) = pq.pop() {
+            if dist > *distances.get(&node).unwrap_or(&f32::MAX) {
+                continue; // a shorter path to node was already settled
+            }
+
+            let outedges = self.weighted_adjacency.get(&node).expect("outedges");
+
+            for (outedge, sentiment) in outedges {
+                let weight = match filter {
+                    SentimentFilter::PositiveOnly if *sentiment < 0.0 => continue,
+                    SentimentFilter::All if *sentiment < 0.0 => Self::NEGATIVE_SENTIMENT_PENALTY,
+                    _ => 1.0,
+                };
+
+                let new_dist = dist + weight;
                 let update = match distances.get(outedge) {
-                    None => {true}
-                    Some(d) => {new_dist < *d}
+                    None => true,
+                    Some(d) => new_dist < *d,
                 };
                 if update {
                     distances.insert(outedge.to_string(), new_dist);
-                    pq.push(Reverse((outedge.clone(), new_dist)))
+                    pq.push(Reverse((HeapDistance(new_dist), outedge.clone())));
                 }
             }
         }
         distances
     }
+
+    // shared by subgraph_in_range and positive_subgraph: builds a new Graph from
+    // only the edges for which keep(TIMESTAMP, LINK_SENTIMENT) holds, with
+    // nodes/size/adjacency_dict/weighted_adjacency recomputed from the survivors.
+    fn subgraph_where<F: Fn(u64, f32) -> bool>(&self, keep: F) -> Graph {
+        let mut subgraph = Graph::new();
+
+        for (source, target, timestamp, sentiment) in &self.edges {
+            if !keep(*timestamp, *sentiment) {
+                continue;
+            }
+
+            subgraph.nodes.insert(source.clone());
+            subgraph.nodes.insert(target.clone());
+            subgraph.edges.push((source.clone(), target.clone(), *timestamp, *sentiment));
+        }
+
+        for node in &subgraph.nodes {
+            subgraph.adjacency_dict.insert(node.clone(), Vec::new());
+            subgraph.weighted_adjacency.insert(node.clone(), Vec::new());
+        }
+
+        for (source, target, _, sentiment) in &subgraph.edges {
+            subgraph.adjacency_dict.get_mut(source).expect("adjacency_dict").push(target.clone());
+            subgraph.weighted_adjacency.get_mut(source).expect("weighted_adjacency").push((target.clone(), *sentiment));
+        }
+
+        subgraph.size = subgraph.nodes.len();
+        subgraph
+    }
+
+    pub fn subgraph_in_range(&self, start: u64, end: u64) -> Graph {
+        /*
+        pub fn subgraph_in_range:
+            builds a new Graph containing only the edges whose TIMESTAMP falls
+            within [start, end], with nodes/size/adjacency_dict/weighted_adjacency
+            recomputed from the surviving edges. Because every centrality
+            method operates on a Graph, running one on the result unlocks
+            longitudinal analysis over an arbitrary slice of the dataset.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+            start: the inclusive lower bound of the timestamp window (Unix seconds)
+            end: the inclusive upper bound of the timestamp window (Unix seconds)
+        returns:
+            subgraph: a new Graph built only from edges with start <= TIMESTAMP <= end
+        */
+
+        self.subgraph_where(|timestamp, _| timestamp >= start && timestamp <= end)
+    }
+
+    pub fn positive_subgraph(&self) -> Graph {
+        /*
+        pub fn positive_subgraph:
+            builds a new Graph containing only the edges with non-negative
+            LINK_SENTIMENT, with nodes/size/adjacency_dict/weighted_adjacency
+            recomputed from the surviving edges. Running closeness_centrality
+            or betweenness_centrality on the result, alongside the same call
+            on self, compares the full network against only its positive
+            hyperlink subnetwork.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+        returns:
+            subgraph: a new Graph built only from edges with LINK_SENTIMENT >= 0.0
+        */
+
+        self.subgraph_where(|_, sentiment| sentiment >= 0.0)
+    }
+
+    // Graphs at or above this many nodes run the per-source Brandes pass in
+    // parallel; smaller graphs stay serial since spinning up rayon's pool
+    // costs more than the BFS itself.
+    const PARALLEL_THRESHOLD: usize = 1_000;
+
+    pub fn betweenness_centrality(&self) -> Vec<(String, f32)> {
+        /*
+        pub fn betweenness_centrality:
+            calculates the (directed) betweenness centrality of every node in
+            the graph using Brandes' algorithm, measuring how often a node
+            lies on shortest paths between other pairs of nodes. Because the
+            graph is directed, scores are not halved the way an undirected
+            implementation would.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+        returns:
+            centrality: an N dimensional vector of (String, f32) where String
+                        is the node and f32 is its betweenness centrality,
+                        sorted in descending order by betweenness centrality.
+        */
+
+        let index = self.build_index();
+        let node_count = index.node_count();
+
+        let scores: Vec<f32> = if node_count >= Self::PARALLEL_THRESHOLD {
+            (0..node_count)
+                .into_par_iter()
+                .map(|source_id| Self::brandes_from_source(&index, source_id))
+                .reduce(|| vec![0.0; node_count], Self::combine_scores)
+        } else {
+            (0..node_count).fold(vec![0.0; node_count], |acc, source_id| {
+                Self::combine_scores(acc, Self::brandes_from_source(&index, source_id))
+            })
+        };
+
+        let mut centrality: Vec<(String, f32)> = (0..node_count)
+            .map(|id| (index.id_to_name[id].clone(), scores[id]))
+            .collect();
+
+        centrality.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        centrality
+    }
+
+    fn combine_scores(mut acc: Vec<f32>, partial: Vec<f32>) -> Vec<f32> {
+        // merges the per-source dependency scores produced by brandes_from_source
+        // into a running total, adding rather than overwriting shared ids.
+        for (total, delta) in acc.iter_mut().zip(partial.iter()) {
+            *total += delta;
+        }
+        acc
+    }
+
+    fn brandes_from_source(index: &GraphIndex, source_id: usize) -> Vec<f32> {
+        /*
+        fn brandes_from_source:
+            runs a single source pass of Brandes' algorithm over the CSR edge
+            layout: an unweighted BFS from source_id that records each node's
+            distance, shortest path count (sigma) and predecessors, followed
+            by a back-propagation over the BFS stack that accumulates each
+            node's dependency on source_id.
+        parameters:
+            index: the GraphIndex to traverse
+            source_id: the id of the node from which shortest paths are measured
+        returns:
+            betweenness: a vector, indexed by id, of the dependency score
+                        source_id contributed to every other node
+        */
+
+        let n = index.node_count();
+        let mut dist: Vec<i64> = vec![-1; n];
+        let mut sigma: Vec<f64> = vec![0.0; n];
+        let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+        dist[source_id] = 0;
+        sigma[source_id] = 1.0;
+
+        let mut stack: Vec<usize> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(source_id);
+
+        while let Some(node) = queue.pop_front() {
+            stack.push(node);
+
+            for &neighbor in index.neighbors(node) {
+                if dist[neighbor] < 0 {
+                    dist[neighbor] = dist[node] + 1;
+                    queue.push_back(neighbor);
+                }
+                if dist[neighbor] == dist[node] + 1 {
+                    sigma[neighbor] += sigma[node];
+                    pred[neighbor].push(node);
+                }
+            }
+        }
+
+        let mut delta: Vec<f64> = vec![0.0; n];
+        let mut betweenness: Vec<f32> = vec![0.0; n];
+
+        while let Some(w) = stack.pop() {
+            let sigma_w = sigma[w];
+            let delta_w = delta[w];
+
+            for &v in &pred[w] {
+                delta[v] += (sigma[v] / sigma_w) * (1.0 + delta_w);
+            }
+
+            if w != source_id {
+                betweenness[w] = delta[w] as f32;
+            }
+        }
+
+        betweenness
+    }
+
+    pub fn pagerank(&self, damping: f32, max_iter: usize, tol: f32) -> Vec<(String, f32)> {
+        /*
+        pub fn pagerank:
+            calculates the PageRank of every node in the graph: a recursive
+            measure of influence where a node's rank depends on the rank of
+            the nodes that link to it. Dangling nodes (zero out-degree)
+            redistribute their rank mass uniformly across every node each
+            iteration so total rank is conserved.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+            damping: the damping factor (probability of following an outgoing
+                    link rather than jumping to a random node), typically 0.85
+            max_iter: the maximum number of iterations to run before stopping
+            tol: the L1 convergence tolerance; iteration stops early once the
+                total change in rank across all nodes falls below this value
+        returns:
+            ranks: an N dimensional vector of (String, f32) where String is
+                the node and f32 is its PageRank, sorted in descending order
+                by rank.
+        */
+
+        let index = self.build_index();
+        let incoming = index.incoming();
+        let node_count = index.node_count();
+        let big_n = node_count as f32;
+
+        let out_degree: Vec<f32> = (0..node_count).map(|id| index.out_degree(id) as f32).collect();
+        let mut rank: Vec<f32> = vec![1.0 / big_n; node_count];
+
+        for _ in 0..max_iter {
+            let dangling_mass: f32 = (0..node_count)
+                .filter(|&id| out_degree[id] == 0.0)
+                .map(|id| rank[id])
+                .sum();
+
+            let mut new_rank: Vec<f32> = vec![0.0; node_count];
+            let mut change = 0.0;
+
+            for id in 0..node_count {
+                let mut incoming_rank = 0.0;
+                for &source in &incoming[id] {
+                    incoming_rank += rank[source] / out_degree[source];
+                }
+
+                let value = (1.0 - damping) / big_n + damping * (incoming_rank + dangling_mass / big_n);
+                change += (value - rank[id]).abs();
+                new_rank[id] = value;
+            }
+
+            rank = new_rank;
+            if change < tol {
+                break;
+            }
+        }
+
+        let mut ranks: Vec<(String, f32)> = (0..node_count).map(|id| (index.id_to_name[id].clone(), rank[id])).collect();
+        ranks.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranks
+    }
+
+    pub fn weakly_connected_components(&self) -> Vec<HashSet<String>> {
+        /*
+        pub fn weakly_connected_components:
+            groups nodes into weakly connected components by treating every
+            edge as undirected: each node is unioned with its out-neighbors
+            in a union-find structure keyed by node string, then nodes are
+            grouped by their root.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+        returns:
+            components: a vector of HashSets of node names, one per weakly
+                        connected component, sorted largest-first.
+        */
+
+        let mut parent: HashMap<String, String> = HashMap::new();
+        for node in &self.nodes {
+            parent.insert(node.clone(), node.clone());
+        }
+
+        for (node, edges) in &self.adjacency_dict {
+            for edge in edges {
+                Self::union(&mut parent, node, edge);
+            }
+        }
+
+        let mut groups: HashMap<String, HashSet<String>> = HashMap::new();
+        for node in &self.nodes {
+            let root = Self::find(&mut parent, node);
+            groups.entry(root).or_default().insert(node.clone());
+        }
+
+        let mut components: Vec<HashSet<String>> = groups.into_values().collect();
+        components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+        components
+    }
+
+    fn find(parent: &mut HashMap<String, String>, node: &str) -> String {
+        // finds the root of node's union-find set, compressing the path as it goes
+        let mut root = node.to_string();
+        while parent[&root] != root {
+            root = parent[&root].clone();
+        }
+
+        let mut current = node.to_string();
+        while current != root {
+            let next = parent[&current].clone();
+            parent.insert(current, root.clone());
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(parent: &mut HashMap<String, String>, a: &str, b: &str) {
+        // merges a's and b's union-find sets together
+        let root_a = Self::find(parent, a);
+        let root_b = Self::find(parent, b);
+        if root_a != root_b {
+            parent.insert(root_a, root_b);
+        }
+    }
+
+    pub fn strongly_connected_components(&self) -> Vec<HashSet<String>> {
+        /*
+        pub fn strongly_connected_components:
+            groups nodes into strongly connected components using Kosaraju's
+            algorithm: a first DFS over the graph records nodes in order of
+            finishing time, then a second DFS pops that order and explores
+            reverse() from each unassigned node, with each resulting tree
+            forming one SCC.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+        returns:
+            components: a vector of HashSets of node names, one per strongly
+                        connected component, sorted largest-first.
+        */
+
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut finish_order: Vec<String> = Vec::new();
+
+        for node in &self.nodes {
+            if visited.contains(node) {
+                continue;
+            }
+
+            // iterative DFS: a node is pushed onto finish_order once every
+            // one of its outgoing edges has been explored
+            visited.insert(node.clone());
+            let mut call_stack: Vec<(String, usize)> = vec![(node.clone(), 0)];
+
+            while let Some((current, next_edge)) = call_stack.pop() {
+                let outedges = self.adjacency_dict.get(&current).expect("outedges");
+                if next_edge < outedges.len() {
+                    let neighbor = outedges[next_edge].clone();
+                    call_stack.push((current, next_edge + 1));
+                    if !visited.contains(&neighbor) {
+                        visited.insert(neighbor.clone());
+                        call_stack.push((neighbor, 0));
+                    }
+                } else {
+                    finish_order.push(current);
+                }
+            }
+        }
+
+        let reverse = self.reverse();
+        let mut assigned: HashSet<String> = HashSet::new();
+        let mut components: Vec<HashSet<String>> = Vec::new();
+
+        for node in finish_order.into_iter().rev() {
+            if assigned.contains(&node) {
+                continue;
+            }
+
+            let mut component: HashSet<String> = HashSet::new();
+            assigned.insert(node.clone());
+            let mut stack = vec![node];
+
+            while let Some(current) = stack.pop() {
+                let outedges = reverse.adjacency_dict.get(&current).expect("outedges");
+                for outedge in outedges {
+                    if !assigned.contains(outedge) {
+                        assigned.insert(outedge.clone());
+                        stack.push(outedge.clone());
+                    }
+                }
+                component.insert(current);
+            }
+
+            components.push(component);
+        }
+
+        components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+        components
+    }
+
+    pub fn to_dot(&self, settings: &DotSettings) -> String {
+        /*
+        pub fn to_dot:
+            serializes the graph as a Graphviz `digraph`, emitting one
+            `"src" -> "dst";` line per edge plus, when settings carries node
+            scores, a `weight` attribute per node so a renderer can size or
+            color nodes by centrality.
+        parameters:
+            &self: a reference to the Graph struct that the method is called on
+            settings: a DotSettings struct carrying optional graph/node/edge
+                    attribute strings and an optional per-node score map
+        returns:
+            dot: a String containing the graph in Graphviz DOT format, ready
+                to be piped into `dot -Tsvg` or similar
+        */
+
+        let mut dot = String::from("digraph {\n");
+
+        if let Some(graph_attributes) = settings.graph_attributes {
+            dot.push_str(&format!("    graph [{}];\n", graph_attributes));
+        }
+        if let Some(node_attributes) = settings.node_attributes {
+            dot.push_str(&format!("    node [{}];\n", node_attributes));
+        }
+        if let Some(edge_attributes) = settings.edge_attributes {
+            dot.push_str(&format!("    edge [{}];\n", edge_attributes));
+        }
+
+        if let Some(scores) = settings.node_scores {
+            for node in &self.nodes {
+                if let Some(score) = scores.get(node) {
+                    dot.push_str(&format!("    \"{}\" [weight={}];\n", Self::quote_dot(node), score));
+                }
+            }
+        }
+
+        for (node, edges) in &self.adjacency_dict {
+            for edge in edges {
+                dot.push_str(&format!("    \"{}\" -> \"{}\";\n", Self::quote_dot(node), Self::quote_dot(edge)));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn quote_dot(name: &str) -> String {
+        // escapes backslashes and double quotes so names embed safely in a quoted DOT id
+        name.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
+
+pub struct DotSettings<'a> {
+    /*
+    pub struct DotSettings: settings controlling how Graph::to_dot renders the graph
+    fields:
+        graph_attributes: an optional Graphviz attribute list applied to the graph itself
+        node_attributes: an optional Graphviz attribute list applied to every node
+        edge_attributes: an optional Graphviz attribute list applied to every edge
+        node_scores: an optional map from node name to a centrality score,
+                    written out as a per-node `weight` attribute so nodes can
+                    be sized or colored by rank
+    */
+    pub graph_attributes: Option<&'a str>,
+    pub node_attributes: Option<&'a str>,
+    pub edge_attributes: Option<&'a str>,
+    pub node_scores: Option<&'a HashMap<String, f32>>,
+}
+
+impl<'a> DotSettings<'a> {
+    pub fn new() -> DotSettings<'a> {
+        // Initializes and returns a DotSettings struct with no attributes or scores set
+        DotSettings { graph_attributes: None, node_attributes: None, edge_attributes: None, node_scores: None }
+    }
 }
 
 #[test]
@@ -285,7 +983,14 @@ fn test_shortest_paths() {
     graph.adjacency_dict.insert("B".to_string(), vec!["C".to_string()]);
     graph.adjacency_dict.insert("C".to_string(), vec![]);
 
-    let distances = graph.shortest_paths("A".to_string());
+    let index = graph.build_index();
+    let start_id = index.id_to_name.iter().position(|name| name == "A").unwrap();
+    let dist = Graph::shortest_paths_from_id(&index, start_id);
+
+    let mut distances: HashMap<String, i64> = HashMap::new();
+    for (id, &d) in dist.iter().enumerate() {
+        distances.insert(index.id_to_name[id].clone(), d);
+    }
 
     let mut expected_distances = HashMap::new();
     expected_distances.insert("A".to_string(), 0);
@@ -293,4 +998,189 @@ fn test_shortest_paths() {
     expected_distances.insert("C".to_string(), 2);
 
     assert_eq!(distances, expected_distances);
+}
+
+#[test]
+fn test_betweenness_centrality() {
+    let mut graph = Graph::new();
+
+    graph.nodes.insert("A".to_string());
+    graph.nodes.insert("B".to_string());
+    graph.nodes.insert("C".to_string());
+    graph.size = 3;
+
+    graph.adjacency_dict.insert("A".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("B".to_string(), vec!["C".to_string()]);
+    graph.adjacency_dict.insert("C".to_string(), vec![]);
+
+    // A -> B -> C: every shortest path between A and C passes through B.
+    let scores: HashMap<String, f32> = graph.betweenness_centrality().into_iter().collect();
+
+    assert_eq!(scores.get("A"), Some(&0.0));
+    assert_eq!(scores.get("B"), Some(&1.0));
+    assert_eq!(scores.get("C"), Some(&0.0));
+}
+
+#[test]
+fn test_pagerank_symmetric_cycle() {
+    let mut graph = Graph::new();
+
+    graph.nodes.insert("A".to_string());
+    graph.nodes.insert("B".to_string());
+    graph.nodes.insert("C".to_string());
+    graph.size = 3;
+
+    graph.adjacency_dict.insert("A".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("B".to_string(), vec!["C".to_string()]);
+    graph.adjacency_dict.insert("C".to_string(), vec!["A".to_string()]);
+
+    // A fully symmetric cycle should converge to an equal rank for every node.
+    let ranks = graph.pagerank(0.85, 100, 1e-6);
+
+    for (_, rank) in &ranks {
+        assert!((rank - 1.0 / 3.0).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_weakly_connected_components() {
+    let mut graph = Graph::new();
+
+    for node in ["A", "B", "C", "D"] {
+        graph.nodes.insert(node.to_string());
+    }
+    graph.size = 4;
+
+    // A and C both point at B, so {A, B, C} are weakly connected; D is isolated.
+    graph.adjacency_dict.insert("A".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("B".to_string(), vec![]);
+    graph.adjacency_dict.insert("C".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("D".to_string(), vec![]);
+
+    let components = graph.weakly_connected_components();
+
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0], HashSet::from(["A".to_string(), "B".to_string(), "C".to_string()]));
+    assert_eq!(components[1], HashSet::from(["D".to_string()]));
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let mut graph = Graph::new();
+
+    for node in ["A", "B", "C", "D"] {
+        graph.nodes.insert(node.to_string());
+    }
+    graph.size = 4;
+
+    // A -> B -> C -> A forms a single SCC; D is only reachable from the cycle, never back.
+    graph.adjacency_dict.insert("A".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("B".to_string(), vec!["C".to_string()]);
+    graph.adjacency_dict.insert("C".to_string(), vec!["A".to_string(), "D".to_string()]);
+    graph.adjacency_dict.insert("D".to_string(), vec![]);
+
+    let components = graph.strongly_connected_components();
+
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0], HashSet::from(["A".to_string(), "B".to_string(), "C".to_string()]));
+    assert_eq!(components[1], HashSet::from(["D".to_string()]));
+}
+
+#[test]
+fn test_to_dot() {
+    let mut graph = Graph::new();
+
+    graph.nodes.insert("A".to_string());
+    graph.nodes.insert("B".to_string());
+    graph.size = 2;
+
+    graph.adjacency_dict.insert("A".to_string(), vec!["B".to_string()]);
+    graph.adjacency_dict.insert("B".to_string(), vec![]);
+
+    let mut scores = HashMap::new();
+    scores.insert("A".to_string(), 0.5_f32);
+
+    let settings = DotSettings { node_scores: Some(&scores), ..DotSettings::new() };
+    let dot = graph.to_dot(&settings);
+
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.contains("\"A\" -> \"B\";"));
+    assert!(dot.contains("\"A\" [weight=0.5];"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn test_weighted_shortest_paths_all_penalizes_negative_sentiment() {
+    let mut graph = Graph::new();
+
+    graph.nodes.insert("A".to_string());
+    graph.nodes.insert("B".to_string());
+    graph.size = 2;
+
+    // A's only link to B carries negative sentiment.
+    graph.weighted_adjacency.insert("A".to_string(), vec![("B".to_string(), -1.0)]);
+    graph.weighted_adjacency.insert("B".to_string(), vec![]);
+
+    let distances = graph.weighted_shortest_paths("A".to_string(), SentimentFilter::All);
+
+    assert_eq!(distances.get("A"), Some(&0.0));
+    assert_eq!(distances.get("B"), Some(&Graph::NEGATIVE_SENTIMENT_PENALTY));
+}
+
+#[test]
+fn test_weighted_shortest_paths_positive_only_excludes_negative_sentiment() {
+    let mut graph = Graph::new();
+
+    graph.nodes.insert("A".to_string());
+    graph.nodes.insert("B".to_string());
+    graph.size = 2;
+
+    graph.weighted_adjacency.insert("A".to_string(), vec![("B".to_string(), -1.0)]);
+    graph.weighted_adjacency.insert("B".to_string(), vec![]);
+
+    let distances = graph.weighted_shortest_paths("A".to_string(), SentimentFilter::PositiveOnly);
+
+    assert_eq!(distances.get("A"), Some(&0.0));
+    assert_eq!(distances.get("B"), None);
+}
+
+#[test]
+fn test_subgraph_in_range() {
+    let mut graph = Graph::new();
+
+    graph.edges.push(("A".to_string(), "B".to_string(), 100, 1.0));
+    graph.edges.push(("B".to_string(), "C".to_string(), 200, 1.0));
+    graph.edges.push(("C".to_string(), "D".to_string(), 300, 1.0));
+
+    let subgraph = graph.subgraph_in_range(150, 250);
+
+    assert_eq!(subgraph.size, 2);
+    assert_eq!(subgraph.nodes, HashSet::from(["B".to_string(), "C".to_string()]));
+    assert_eq!(subgraph.adjacency_dict.get("B"), Some(&vec!["C".to_string()]));
+    assert_eq!(subgraph.weighted_adjacency.get("B"), Some(&vec![("C".to_string(), 1.0)]));
+}
+
+#[test]
+fn test_positive_subgraph() {
+    let mut graph = Graph::new();
+
+    graph.edges.push(("A".to_string(), "B".to_string(), 100, 1.0));
+    graph.edges.push(("B".to_string(), "C".to_string(), 200, -1.0));
+    graph.edges.push(("C".to_string(), "D".to_string(), 300, 1.0));
+
+    let subgraph = graph.positive_subgraph();
+
+    assert_eq!(subgraph.size, 4);
+    assert_eq!(subgraph.nodes, HashSet::from(["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()]));
+    assert_eq!(subgraph.adjacency_dict.get("A"), Some(&vec!["B".to_string()]));
+    assert_eq!(subgraph.adjacency_dict.get("B"), Some(&Vec::new()));
+    assert_eq!(subgraph.adjacency_dict.get("C"), Some(&vec!["D".to_string()]));
+}
+
+#[test]
+fn test_parse_timestamp() {
+    assert_eq!(parse_timestamp("1970-01-01 00:00:00"), 0);
+    assert_eq!(parse_timestamp("1970-01-02 00:00:00"), 86_400);
+    assert_eq!(parse_timestamp("2001-01-01 00:00:00"), 978_307_200);
+    assert_eq!(parse_timestamp("2016-07-01 12:34:56"), 1_467_376_496);
 }
\ No newline at end of file